@@ -6,44 +6,149 @@ use bevy::{
 };
 use std::ops::{Deref, DerefMut};
 
+/// A typed key identifying a schedule stage, standing in for Bevy's string-based
+/// stage constants (`bevy::app::stage::*`) so stage references are checked at
+/// compile time instead of silently no-op'ing on a typo'd `&str`.
+pub trait StageLabel: Send + Sync + 'static {
+    fn label(&self) -> &'static str;
+}
+
+/// The built-in Bevy schedule stages. Prefer this over the raw
+/// `bevy::app::stage::*` string constants: a typo like `Stage::Udpate` fails to
+/// compile, where a misspelled `stage::*` string would silently add a stage that
+/// nothing ever runs systems in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Stage {
+    First,
+    PreEvent,
+    Event,
+    PreUpdate,
+    Update,
+    PostUpdate,
+    Last,
+}
+
+impl StageLabel for Stage {
+    fn label(&self) -> &'static str {
+        match self {
+            Stage::First => stage::FIRST,
+            Stage::PreEvent => stage::PRE_EVENT,
+            Stage::Event => stage::EVENT,
+            Stage::PreUpdate => stage::PRE_UPDATE,
+            Stage::Update => stage::UPDATE,
+            Stage::PostUpdate => stage::POST_UPDATE,
+            Stage::Last => stage::LAST,
+        }
+    }
+}
+
+/// Kept so a runner can still reach a custom stage that isn't one of the
+/// built-in [`Stage`] variants, e.g. one added by a third-party plugin. Prefer
+/// [`Stage`] for the built-ins, since a typo'd string here still compiles and
+/// silently no-ops exactly as before.
+impl StageLabel for &'static str {
+    fn label(&self) -> &'static str {
+        self
+    }
+}
+
+/// A predicate that gates whether a [`PackedSchedule`] runs on a given call.
+///
+/// Evaluated once per invocation against the live `World`/`Resources`, mirroring
+/// Bevy's stageless run conditions.
+pub type RunCondition = Box<dyn FnMut(&World, &Resources) -> bool + Send + Sync>;
+
 /// Determines how the schedule should run
 #[derive(Debug, Copy, Clone)]
 pub enum ScheduleType {
     // The Schedule runs with...
     // ... Every frame
     Always,
-    // ... A fixed tick cycle
-    Fixed(f64, f64), // (rate, accumulator)
+    // ... A fixed tick cycle, with an optional cap on ticks run per call to guard
+    // against the spiral of death (a tick taking longer than `rate` growing the
+    // accumulator faster than it can ever drain)
+    Fixed(f64, f64, Option<u32>), // (rate, accumulator, max_ticks_per_run)
+}
+
+/// Whether a [`PackedSchedule`] is currently ticking, frozen, or should advance by
+/// exactly one tick before freezing again. Borrowed from Bevy's system-stepping, for
+/// frame-by-frame debugging of a schedule.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SchedulePower {
+    Running,
+    Paused,
+    StepOnce,
+}
 
-                     // TODO: Figure out how to make this more useful?
-                     // ... A user-provided fn
-                     // With(Box<dyn FnMut(&mut PackedSchedule, &mut World, &mut Resources) + Send + Sync>),
+impl Default for SchedulePower {
+    fn default() -> Self {
+        SchedulePower::Running
+    }
 }
 
 /// The PackedSchedule is responsible for actual execution
 /// You probably won't need to touch this directly
 #[derive(Debug)]
-pub struct PackedSchedule(pub ScheduleType, pub Schedule, ParallelExecutor);
+pub struct PackedSchedule {
+    pub schedule_type: ScheduleType,
+    pub schedule: Schedule,
+    executor: ParallelExecutor,
+    condition: Option<RunCondition>,
+    power: SchedulePower,
+    // Systems here run every call regardless of `power`, for things like
+    // rendering/debug UI that should keep going while the rest of the schedule
+    // is paused or stepping.
+    always_schedule: Schedule,
+    always_executor: ParallelExecutor,
+}
 
 impl Default for PackedSchedule {
     fn default() -> Self {
-        PackedSchedule(
-            ScheduleType::Always,
-            Default::default(),
-            ParallelExecutor::without_tracker_clears(),
-        )
+        let mut always_schedule = Schedule::default();
+        always_schedule.add_stage(Stage::Update.label());
+        PackedSchedule {
+            schedule_type: ScheduleType::Always,
+            schedule: Default::default(),
+            executor: ParallelExecutor::without_tracker_clears(),
+            condition: None,
+            power: Default::default(),
+            always_schedule,
+            always_executor: ParallelExecutor::without_tracker_clears(),
+        }
     }
 }
 
 impl PackedSchedule {
     fn run(&mut self, mut world: &mut World, mut resources: &mut Resources) {
-        self.1.initialize(world, resources);
+        self.schedule.initialize(world, resources);
+        self.always_schedule.initialize(world, resources);
 
-        match &mut self.0 {
+        // A condition gates the whole schedule, including systems that opted out
+        // of stepping: `run_if` is meant for things like "only while connected to
+        // the server", where the always-run systems have nothing valid to act on
+        // either. While it returns `false` we skip running entirely, leaving
+        // `ScheduleType::Fixed`'s accumulator untouched so resuming doesn't lose
+        // or dump accumulated time.
+        if let Some(condition) = &mut self.condition {
+            if !condition(world, resources) {
+                return;
+            }
+        }
+
+        // Systems opted out of stepping run whatever `power` is, even while paused.
+        self.always_executor
+            .run(&mut self.always_schedule, &mut world, &mut resources);
+
+        if self.power == SchedulePower::Paused {
+            return;
+        }
+
+        match &mut self.schedule_type {
             ScheduleType::Always => {
-                self.2.run(&mut self.1, &mut world, &mut resources);
+                self.executor
+                    .run(&mut self.schedule, &mut world, &mut resources);
             }
-            ScheduleType::Fixed(rate, accumulator) => {
+            ScheduleType::Fixed(rate, accumulator, max_ticks) => {
                 // Accumulate time
                 match resources.get::<Time>() {
                     Some(time) => {
@@ -53,25 +158,62 @@ impl PackedSchedule {
                 };
 
                 // Run fixed-interval ticks
-                while accumulator >= rate {
-                    self.2.run(&mut self.1, &mut world, &mut resources);
-                    *accumulator -= *rate;
+                match self.power {
+                    SchedulePower::StepOnce => {
+                        if *accumulator >= *rate {
+                            self.executor
+                                .run(&mut self.schedule, &mut world, &mut resources);
+                            *accumulator -= *rate;
+                        } else {
+                            // Not enough time banked for a tick yet: stay in
+                            // `StepOnce` so a later call still runs the one tick.
+                            return;
+                        }
+                    }
+                    _ => {
+                        let mut ticks_run = 0u32;
+                        while *accumulator >= *rate {
+                            if let Some(max_ticks) = max_ticks {
+                                if ticks_run >= *max_ticks {
+                                    break;
+                                }
+                            }
+                            self.executor
+                                .run(&mut self.schedule, &mut world, &mut resources);
+                            *accumulator -= *rate;
+                            ticks_run += 1;
+                        }
+                        // Spiral-of-death protection: if the cap stopped us short and
+                        // ticks are still piling up, drop the whole backlog ticks
+                        // instead of letting them grow further, but keep the
+                        // sub-tick remainder so `frame_percent` doesn't snap to 0.
+                        if let Some(max_ticks) = max_ticks {
+                            if ticks_run >= *max_ticks && *accumulator >= *rate {
+                                *accumulator %= *rate;
+                            }
+                        }
+                    }
                 }
             }
         };
+
+        if self.power == SchedulePower::StepOnce {
+            self.power = SchedulePower::Paused;
+        }
     }
 
     fn get_dummy(&self) -> Self {
         PackedSchedule {
-            0: self.0,
+            schedule_type: self.schedule_type,
+            power: self.power,
             ..Default::default()
         }
     }
 
     fn frame_percent(&self) -> f64 {
-        match self.0 {
+        match self.schedule_type {
             ScheduleType::Always => 1.0,
-            ScheduleType::Fixed(rate, accumulator) => {
+            ScheduleType::Fixed(rate, accumulator, _) => {
                 f64::min(1.0, f64::max(0.0, accumulator / rate))
             }
         }
@@ -80,15 +222,28 @@ impl PackedSchedule {
 
 /// Responsible for holding the data in Bevy
 /// Use as a Resource or Component
+///
+/// A runner owns one primary [`PackedSchedule`] plus any number of additional
+/// schedules registered with [`ScheduleRunner::add_named_schedule`], each ticking
+/// to its own [`ScheduleType`] independently. This lets a single resource or
+/// component host several per-rate subsystems (e.g. a `"physics"` schedule on
+/// `Fixed` alongside a `"render"` schedule on `Always`) instead of requiring a
+/// separate entity per rate.
 #[derive(Debug)]
-pub struct ScheduleRunner(pub PackedSchedule);
+pub struct ScheduleRunner {
+    primary: PackedSchedule,
+    named: HashMap<&'static str, PackedSchedule>,
+}
 
 impl Default for ScheduleRunner {
     fn default() -> Self {
-        ScheduleRunner(PackedSchedule {
-            0: ScheduleType::Always,
-            ..Default::default()
-        })
+        ScheduleRunner {
+            primary: PackedSchedule {
+                schedule_type: ScheduleType::Always,
+                ..Default::default()
+            },
+            named: HashMap::default(),
+        }
         .add_default_stages()
     }
 }
@@ -97,10 +252,13 @@ impl Default for ScheduleRunner {
 impl ScheduleRunner {
     /// A fixed-rate runner that runs every `rate` seconds
     pub fn from_rate(rate: f64) -> Self {
-        ScheduleRunner(PackedSchedule {
-            0: ScheduleType::Fixed(rate, 0.0),
-            ..Default::default()
-        })
+        ScheduleRunner {
+            primary: PackedSchedule {
+                schedule_type: ScheduleType::Fixed(rate, 0.0, None),
+                ..Default::default()
+            },
+            named: HashMap::default(),
+        }
         .add_default_stages()
     }
 
@@ -109,39 +267,130 @@ impl ScheduleRunner {
         Self::from_rate(1.0 / rate)
     }
 
-    // TODO: Figure out how we should support this stuff
-    // A runner executed by a user-provided fn
-    // pub fn from_fn<F>(f: F) -> Self
-    // where F: FnMut(&mut PackedSchedule, &mut World, &mut Resources) + Send + Sync + 'static {
-    //     ScheduleRunner(PackedSchedule { 0: ScheduleType::With(Box::new(f)) , .. Default::default() })
-    // }
+    /// Cap how many catch-up ticks a `Fixed` schedule will run in a single call,
+    /// guarding against the spiral of death when a tick takes longer than `rate`.
+    /// Any backlog beyond the cap is dropped rather than carried over. No-op for
+    /// `ScheduleType::Always`.
+    pub fn with_max_catch_up(mut self, ticks: u32) -> Self {
+        if let ScheduleType::Fixed(_, _, max_ticks) = &mut self.primary.schedule_type {
+            *max_ticks = Some(ticks);
+        }
+        self
+    }
+
+    /// Gate execution of the whole schedule behind `condition`, evaluated once per
+    /// call of [`schedule_runner_system`] against the live `World`/`Resources`.
+    ///
+    /// While `condition` returns `false` the schedule is skipped entirely: for a
+    /// `Fixed` schedule this means the accumulator simply stops advancing, so a
+    /// paused schedule picks back up exactly where it left off once the condition
+    /// is true again.
+    pub fn run_if<F>(mut self, condition: F) -> Self
+    where
+        F: FnMut(&World, &Resources) -> bool + Send + Sync + 'static,
+    {
+        self.primary.condition = Some(Box::new(condition));
+        self
+    }
 
     pub fn add_default_stages(self) -> Self {
-        self.add_stage(stage::FIRST)
-            .add_stage(stage::PRE_EVENT)
-            .add_stage(stage::EVENT)
-            .add_stage(stage::PRE_UPDATE)
-            .add_stage(stage::UPDATE)
-            .add_stage(stage::POST_UPDATE)
-            .add_stage(stage::LAST)
+        self.add_stage(Stage::First)
+            .add_stage(Stage::PreEvent)
+            .add_stage(Stage::Event)
+            .add_stage(Stage::PreUpdate)
+            .add_stage(Stage::Update)
+            .add_stage(Stage::PostUpdate)
+            .add_stage(Stage::Last)
+    }
+
+    pub fn add_stage<L: StageLabel>(mut self, stage: L) -> Self {
+        self.primary.schedule.add_stage(stage.label());
+        self
+    }
+
+    /// Insert a new stage immediately after `target`.
+    pub fn add_stage_after<T: StageLabel, L: StageLabel>(mut self, target: T, stage: L) -> Self {
+        self.primary
+            .schedule
+            .add_stage_after(target.label(), stage.label());
+        self
+    }
+
+    /// Insert a new stage immediately before `target`.
+    pub fn add_stage_before<T: StageLabel, L: StageLabel>(mut self, target: T, stage: L) -> Self {
+        self.primary
+            .schedule
+            .add_stage_before(target.label(), stage.label());
+        self
     }
 
-    pub fn add_stage(mut self, stage_name: &'static str) -> Self {
-        self.0 .1.add_stage(stage_name);
+    /// Register an additional schedule under `label`, ticked to its own
+    /// [`ScheduleType`] independently of the primary schedule and of every other
+    /// named schedule on this runner.
+    pub fn add_named_schedule(mut self, label: &'static str, schedule: PackedSchedule) -> Self {
+        self.named.insert(label, schedule);
+        self
+    }
+
+    /// Add a system to `stage::UPDATE`.
+    pub fn add_system<S, Params, IntoS>(self, system: IntoS) -> Self
+    where
+        S: System<Input = (), Output = ()>,
+        IntoS: IntoSystem<Params, S>,
+    {
+        self.add_system_to_stage(Stage::Update, system)
+    }
+
+    /// Add a system to a specific stage, rather than always `stage::UPDATE`.
+    pub fn add_system_to_stage<L, S, Params, IntoS>(mut self, stage: L, system: IntoS) -> Self
+    where
+        L: StageLabel,
+        S: System<Input = (), Output = ()>,
+        IntoS: IntoSystem<Params, S>,
+    {
+        self.primary
+            .schedule
+            .add_system_to_stage(stage.label(), system);
         self
     }
 
-    pub fn add_system<S, Params, IntoS>(mut self, system: IntoS) -> Self
+    /// Add a system that keeps running every call, even while this runner is
+    /// paused or being stepped one tick at a time.
+    pub fn add_system_ignore_stepping<S, Params, IntoS>(mut self, system: IntoS) -> Self
     where
         S: System<Input = (), Output = ()>,
         IntoS: IntoSystem<Params, S>,
     {
-        self.0 .1.add_system_to_stage(stage::UPDATE, system);
+        self.primary
+            .always_schedule
+            .add_system_to_stage(Stage::Update.label(), system);
         self
     }
 
     pub fn frame_percent(&self) -> f64 {
-        self.0.frame_percent()
+        self.primary.frame_percent()
+    }
+
+    /// Current running/paused/stepping state of this runner.
+    pub fn power(&self) -> SchedulePower {
+        self.primary.power
+    }
+
+    /// Freeze the schedule: subsequent calls run only the systems added with
+    /// [`Self::add_system_ignore_stepping`] until [`Self::resume`] or [`Self::step`].
+    pub fn pause(&mut self) {
+        self.primary.power = SchedulePower::Paused;
+    }
+
+    /// Let the schedule run normally again after [`Self::pause`].
+    pub fn resume(&mut self) {
+        self.primary.power = SchedulePower::Running;
+    }
+
+    /// Advance the schedule by exactly one tick, then freeze again. For a `Fixed`
+    /// schedule this drains a single accumulator tick rather than catching up.
+    pub fn step(&mut self) {
+        self.primary.power = SchedulePower::StepOnce;
     }
 }
 
@@ -149,13 +398,43 @@ impl ScheduleRunner {
 impl Deref for ScheduleRunner {
     type Target = Schedule;
     fn deref(&self) -> &Self::Target {
-        &self.0 .1
+        &self.primary.schedule
     }
 }
 
 impl DerefMut for ScheduleRunner {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0 .1
+        &mut self.primary.schedule
+    }
+}
+
+/// Pull every schedule (primary plus all named) out of a runner, leaving dummy
+/// placeholders behind so the runner can keep being read (e.g. via
+/// `Res<ScheduleRunner>::frame_percent`) while the real schedules run.
+fn take_schedules(
+    runner: &mut ScheduleRunner,
+) -> (PackedSchedule, Vec<(&'static str, PackedSchedule)>) {
+    let primary_dummy = runner.primary.get_dummy();
+    let primary = std::mem::replace(&mut runner.primary, primary_dummy);
+    let named = runner
+        .named
+        .iter_mut()
+        .map(|(label, schedule)| {
+            let dummy = schedule.get_dummy();
+            (*label, std::mem::replace(schedule, dummy))
+        })
+        .collect();
+    (primary, named)
+}
+
+fn put_schedules(
+    runner: &mut ScheduleRunner,
+    primary: PackedSchedule,
+    named: Vec<(&'static str, PackedSchedule)>,
+) {
+    runner.primary = primary;
+    for (label, schedule) in named {
+        runner.named.insert(label, schedule);
     }
 }
 
@@ -165,27 +444,43 @@ pub fn schedule_runner_system(mut world: &mut World, mut resources: &mut Resourc
     // Run it as a resource
     if resources.contains::<ScheduleRunner>() {
         // rip and tear
-        let mut schedule = {
-            let schedule = &mut resources.get_mut::<ScheduleRunner>().unwrap().0;
-            std::mem::replace(schedule, schedule.get_dummy())
-        };
-        schedule.run(&mut world, &mut resources);
-        resources.get_mut::<ScheduleRunner>().unwrap().0 = schedule;
+        let (mut primary, mut named) =
+            take_schedules(&mut resources.get_mut::<ScheduleRunner>().unwrap());
+        primary.run(&mut world, &mut resources);
+        for (_, schedule) in named.iter_mut() {
+            schedule.run(&mut world, &mut resources);
+        }
+        put_schedules(
+            &mut resources.get_mut::<ScheduleRunner>().unwrap(),
+            primary,
+            named,
+        );
     }
 
     // Run it as a component
     // We take all components, run them, put them back
-    let mut entity_map: HashMap<Entity, PackedSchedule> = world
-        .query_mut::<(Entity, &mut ScheduleRunner)>()
-        .map(|(entity, mut runner)| {
-            let replacement = runner.0.get_dummy();
-            (entity, std::mem::replace(&mut runner.0, replacement))
-        })
-        .collect();
-    for (_, schedule) in entity_map.iter_mut() {
-        schedule.run(&mut world, &mut resources);
+    let mut entity_map: HashMap<Entity, (PackedSchedule, Vec<(&'static str, PackedSchedule)>)> =
+        world
+            .query_mut::<(Entity, &mut ScheduleRunner)>()
+            .map(|(entity, mut runner)| (entity, take_schedules(&mut runner)))
+            .collect();
+
+    // Schedules run serially, one entity after another: a system may spawn or
+    // despawn entities, touch arbitrary component storage, or read a resource
+    // it never declared (every `Fixed` schedule reads `Time`, for instance), so
+    // there is no way to tell from the outside which runners could safely share
+    // `World`/`Resources` across threads. Actually running two of them
+    // concurrently would need Bevy's own `ParallelExecutor`/task pool, which
+    // partitions access at the system level instead of the runner level.
+    for (primary, named) in entity_map.values_mut() {
+        primary.run(&mut world, &mut resources);
+        for (_, schedule) in named.iter_mut() {
+            schedule.run(&mut world, &mut resources);
+        }
     }
+
     for (entity, mut runner) in &mut world.query_mut::<(Entity, &mut ScheduleRunner)>() {
-        runner.0 = entity_map.remove(&entity).unwrap();
+        let (primary, named) = entity_map.remove(&entity).unwrap();
+        put_schedules(&mut runner, primary, named);
     }
 }